@@ -1,4 +1,5 @@
 #![doc(html_root_url = "https://docs.rs/slice-of-array/0.3.0")]
+#![no_std]
 
 //! Extension traits for viewing a slice as a slice of arrays or vice versa.
 //!
@@ -10,6 +11,13 @@
 //!    already provided by a coercion)
 //!  * **`nest_mut`, `flat_mut`, `as_mut_array`** for `&mut [_]`.
 //!
+//! as well as the following conversions on owned `Vec<T>`, which reuse the
+//! original allocation (see [`into_nest`][VecNestExt::into_nest] for when it
+//! cannot):
+//!
+//!  * **[`into_flat`][VecFlatExt::into_flat]**: `Vec<[T; n]> -> Vec<T>`
+//!  * **[`into_nest`][VecNestExt::into_nest]**: `Vec<T> -> Vec<[T; n]>`
+//!
 //! Altogether, these let you swap between arbitrary representations
 //! of contiguous, `T`-aligned streams of `T` data.  For instance,
 //! to view a `[[i32; 6]; 5]` as a `&[[[i32; 3]; 2]; 5]`,
@@ -57,11 +65,39 @@
 //! they are most likely used when bridging the gap between APIs that work
 //! with flattened slices and APIs that work with slices of arrays.
 //!
-//! Zero-cost conversions in owned data (e.g. between `Vec<T>`
-//! and `Vec<[T; n]>`) are not provided, and are probably impossible
-//! in consideration of e.g. custom allocators. If you need to
-//! convert between such types, you can use these traits in tandem
-//! with `<[T]>::to_vec` to perform a copy:
+//! For the cases where the input size genuinely cannot be trusted (e.g.
+//! parsing untrusted input, or other contexts where unwinding is unwelcome),
+//! each of these methods has a fallible, `try_`-prefixed counterpart that
+//! returns a `Result` instead of panicking: [`try_nest`][SliceNestExt::try_nest],
+//! [`try_as_array`][SliceArrayExt::try_as_array] and
+//! [`try_flat`][SliceFlatExt::try_flat] (along with their `_mut` forms). The
+//! panicking methods are defined in terms of these, so there is no loss of
+//! functionality in choosing one form over the other.
+//!
+//! This crate is `#![no_std]`. The core `nest`/`flat`/`as_array` family above
+//! needs nothing but `core`, and is always available. Anything built on
+//! `Vec` lives behind the `alloc` cargo feature (enabled by default, so that
+//! ordinary `std` users don't need to think about it); build with
+//! `default-features = false` to drop the dependency on `alloc` entirely.
+//!
+//! Owned conversions between `Vec<T>` and `Vec<[T; n]>` are also
+//! available, via [`into_flat`][VecFlatExt::into_flat] and
+//! [`into_nest`][VecNestExt::into_nest]. `into_flat` always reuses the
+//! original allocation without copying; `into_nest` does so whenever the
+//! `Vec`'s capacity is a whole number of arrays, and otherwise shrinks or
+//! reallocates the buffer:
+//!
+//! ```
+//! # use ::slice_of_array::prelude::*;
+//! let vec = vec![[2i32, 2, 2], [7, 7, 7]];
+//!
+//! // no copy: reinterprets the existing allocation
+//! let flattened = vec.into_flat();
+//! assert_eq!(flattened, vec![2i32, 2, 2, 7, 7, 7]);
+//! ```
+//!
+//! If you'd rather keep the original `Vec` around, these traits can still
+//! be used in tandem with `<[T]>::to_vec` to perform a copy instead:
 //!
 //! ```
 //! # use ::slice_of_array::prelude::*;
@@ -72,11 +108,36 @@
 //! assert_eq!(flattened, vec![2i32, 2, 2, 7, 7, 7]);
 //! ```
 //!
+//! With the `bytes` cargo feature enabled, `&[V]` (for `V: `[`ByteSafe`]`)
+//! can also be reinterpreted all the way down to `&[u8]` and back, via
+//! [`as_bytes`][SliceBytesExt::as_bytes] and
+//! [`try_from_bytes`][BytesSliceExt::try_from_bytes]. This composes with
+//! `flat`/`nest` as usual, so e.g. a `&[[f32; 3]]` can become a `&[u8]` in
+//! one chain: `slc.flat().as_bytes()`.
+//!
 //! [`nest`]: [`SliceNestExt::nest`]
 //! [`flat`]: [`SliceFlatExt::flat`]
 //! [`as_array`]: [`SliceArrayExt::as_array`]
 
-use std::slice;
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Tests make liberal use of `Vec`/`vec!` just to have something to slice
+// into, independently of the `alloc` cargo feature.
+#[cfg(test)]
+extern crate std;
+#[cfg(all(test, not(feature = "alloc")))]
+pub(crate) use std::vec::Vec;
+#[cfg(test)]
+pub(crate) use std::vec;
+
+use core::fmt;
+#[cfg(any(feature = "alloc", feature = "bytes"))]
+use core::mem;
+use core::slice;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 pub mod prelude {
     //! This module contains extension traits from `slice_of_array`.
@@ -95,6 +156,14 @@ pub mod prelude {
     pub use super::SliceFlatExt;
     pub use super::SliceNestExt;
     pub use super::SliceArrayExt;
+    #[cfg(feature = "alloc")]
+    pub use super::VecFlatExt;
+    #[cfg(feature = "alloc")]
+    pub use super::VecNestExt;
+    #[cfg(feature = "bytes")]
+    pub use super::SliceBytesExt;
+    #[cfg(feature = "bytes")]
+    pub use super::BytesSliceExt;
 }
 
 /// Marker trait used in bounds of `Slice{Flat,Nest,Array}Ext`.
@@ -133,7 +202,7 @@ unsafe impl<T, const N: usize> IsSliceomorphic for [T; N] {
 // Validate some known assumptions of IsSliceomorphic "at runtime,"
 //  in a manner which should get optimized into thin air.
 fn validate_alignment_and_size<V: IsSliceomorphic>() {
-    use std::mem::{align_of, size_of};
+    use core::mem::{align_of, size_of};
 
     assert_eq!(
         align_of::<V::Element>(),
@@ -146,6 +215,24 @@ fn validate_alignment_and_size<V: IsSliceomorphic>() {
     );
 }
 
+/// The error type returned by [`SliceFlatExt::try_flat`] and [`SliceFlatExt::try_flat_mut`].
+///
+/// This type is uninhabited: flattening `&[V]` into `&[V::Element]` cannot fail
+/// for any slice length or any value of `V::LEN`. It exists purely so that
+/// `try_flat`'s signature stays parallel to [`NestError`] and [`ArrayError`],
+/// leaving room for a real failure mode to be added later without a breaking
+/// change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlatError {}
+
+impl fmt::Display for FlatError {
+    fn fmt(&self, _f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {}
+    }
+}
+
+impl core::error::Error for FlatError {}
+
 /// Permits viewing a slice of arrays as a flat slice.
 ///
 /// # Implementors
@@ -171,8 +258,47 @@ pub trait SliceFlatExt<T> {
 
     /// View `&mut [[T; n]]` as `&mut [T]`
     fn flat_mut(&mut self) -> &mut [T];
+
+    /// Fallible form of [`flat`][Self::flat].
+    ///
+    /// This can never actually fail; see [`FlatError`].
+    fn try_flat(&self) -> Result<&[T], FlatError>;
+
+    /// Fallible form of [`flat_mut`][Self::flat_mut].
+    ///
+    /// This can never actually fail; see [`FlatError`].
+    fn try_flat_mut(&mut self) -> Result<&mut [T], FlatError>;
+}
+
+/// The error type returned by [`SliceNestExt::try_nest`] and [`SliceNestExt::try_nest_mut`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NestError {
+    /// `V::LEN` is zero, so the original length of the slice could never be recovered.
+    ZeroLengthArray,
+    /// The slice length is not evenly divisible by `V::LEN`.
+    NotDivisible {
+        /// The length of the slice that was to be nested.
+        len: usize,
+        /// The `V::LEN` that was requested.
+        array_len: usize,
+    },
+}
+
+impl fmt::Display for NestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NestError::ZeroLengthArray => {
+                write!(f, "cannot nest arrays of length 0")
+            },
+            NestError::NotDivisible { len, array_len } => {
+                write!(f, "cannot view slice of length {} as [[_; {}]]", len, array_len)
+            },
+        }
+    }
 }
 
+impl core::error::Error for NestError {}
+
 /// Permits viewing a slice as a slice of arrays.
 ///
 /// The new array dimension can often be inferred.
@@ -204,8 +330,42 @@ pub trait SliceNestExt<T> {
 
     /// View `&mut [T]` as `&mut [[T; n]]` without copying.
     fn nest_mut<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut [V];
+
+    /// Fallible form of [`nest`][Self::nest].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if `V::LEN` is zero, or if the
+    /// slice length is not divisible by `V::LEN`. See [`NestError`].
+    fn try_nest<V: IsSliceomorphic<Element=T>>(&self) -> Result<&[V], NestError>;
+
+    /// Fallible form of [`nest_mut`][Self::nest_mut].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if `V::LEN` is zero, or if the
+    /// slice length is not divisible by `V::LEN`. See [`NestError`].
+    fn try_nest_mut<V: IsSliceomorphic<Element=T>>(&mut self) -> Result<&mut [V], NestError>;
+}
+
+/// The error type returned by [`SliceArrayExt::try_as_array`] and
+/// [`SliceArrayExt::try_as_mut_array`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArrayError {
+    /// The length of the slice that was to be viewed as an array.
+    pub len: usize,
+    /// The `V::LEN` that was requested.
+    pub array_len: usize,
+}
+
+impl fmt::Display for ArrayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot view slice of length {} as [_; {}]", self.len, self.array_len)
+    }
 }
 
+impl core::error::Error for ArrayError {}
+
 /// Permits viewing a slice as an array.
 ///
 /// The output array length can often be inferred.
@@ -235,6 +395,22 @@ pub trait SliceArrayExt<T> {
     /// View `&mut [T]` as `&mut [T; n]`.
     fn as_mut_array<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut V;
 
+    /// Fallible form of [`as_array`][Self::as_array].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if the slice is not exactly the
+    /// requested length. See [`ArrayError`].
+    fn try_as_array<V: IsSliceomorphic<Element=T>>(&self) -> Result<&V, ArrayError>;
+
+    /// Fallible form of [`as_mut_array`][Self::as_mut_array].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if the slice is not exactly the
+    /// requested length. See [`ArrayError`].
+    fn try_as_mut_array<V: IsSliceomorphic<Element=T>>(&mut self) -> Result<&mut V, ArrayError>;
+
     /// Clone `&[T]` to `[T; n]`.
     ///
     /// This is provided because `.as_array().clone()` tends to cause trouble for
@@ -245,85 +421,115 @@ pub trait SliceArrayExt<T> {
 
 impl<V: IsSliceomorphic> SliceFlatExt<V::Element> for [V] {
     fn flat(&self) -> &[V::Element] {
-        // UNSAFETY: (::std::slice::from_raw_parts)
+        match self.try_flat() {
+            Ok(flat) => flat,
+            Err(e) => match e {},
+        }
+    }
+
+    fn flat_mut(&mut self) -> &mut [V::Element] {
+        match self.try_flat_mut() {
+            Ok(flat) => flat,
+            Err(e) => match e {},
+        }
+    }
+
+    fn try_flat(&self) -> Result<&[V::Element], FlatError> {
+        validate_alignment_and_size::<V>();
+
+        // UNSAFETY: (core::slice::from_raw_parts)
         // - pointer must be non-null (even for zero-length)
         // - pointer must be aligned
         // - pointer must be valid for given size
         // - lifetimes are unchecked
         unsafe {
-            validate_alignment_and_size::<V>();
-            slice::from_raw_parts(
+            Ok(slice::from_raw_parts(
                 self.as_ptr() as *const _,
                 self.len() * V::LEN,
-            )
+            ))
         }
     }
 
-    fn flat_mut(&mut self) -> &mut [V::Element] {
-        // UNSAFETY: (::std::slice::from_raw_parts_mut)
+    fn try_flat_mut(&mut self) -> Result<&mut [V::Element], FlatError> {
+        validate_alignment_and_size::<V>();
+
+        // UNSAFETY: (core::slice::from_raw_parts_mut)
         // - pointer must be non-null (even for zero-length)
         // - pointer must be aligned
         // - pointer must be valid for given size
         // - lifetimes are unchecked
         // - aliasing guarantees of &mut are unchecked
         unsafe {
-            validate_alignment_and_size::<V>();
-            slice::from_raw_parts_mut(
+            Ok(slice::from_raw_parts_mut(
                 self.as_mut_ptr() as *mut _,
                 self.len() * V::LEN,
-            )
+            ))
         }
     }
 }
 
 impl<T> SliceNestExt<T> for [T] {
     fn nest<V: IsSliceomorphic<Element=T>>(&self) -> &[V] {
-        validate_nest_assumptions::<V>(self.len(), "&");
+        self.try_nest().unwrap_or_else(|e| panic!("{}", e))
+    }
 
-        // UNSAFETY: (std::slice::from_raw_parts)
+    fn nest_mut<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut [V] {
+        self.try_nest_mut().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn try_nest<V: IsSliceomorphic<Element=T>>(&self) -> Result<&[V], NestError> {
+        validate_nest_assumptions::<V>(self.len())?;
+
+        // UNSAFETY: (core::slice::from_raw_parts)
         // - pointer must be non-null (even for zero-length)
         // - pointer must be aligned
         // - pointer must be valid for given size
         // - lifetimes are unchecked
-        unsafe { slice::from_raw_parts(
+        unsafe { Ok(slice::from_raw_parts(
             self.as_ptr() as *const _,
             self.len() / V::LEN,
-        )}
+        ))}
     }
 
-    fn nest_mut<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut [V] {
-        validate_nest_assumptions::<V>(self.len(), "&mut ");
+    fn try_nest_mut<V: IsSliceomorphic<Element=T>>(&mut self) -> Result<&mut [V], NestError> {
+        validate_nest_assumptions::<V>(self.len())?;
 
-        // UNSAFETY: (std::slice::from_raw_parts_mut)
+        // UNSAFETY: (core::slice::from_raw_parts_mut)
         // - pointer must be non-null (even for zero-length)
         // - pointer must be aligned
         // - pointer must be valid for given size
         // - lifetimes are unchecked
         // - aliasing guarantees of &mut are unchecked
-        unsafe { slice::from_raw_parts_mut(
+        unsafe { Ok(slice::from_raw_parts_mut(
             self.as_mut_ptr() as *mut _,
             self.len() / V::LEN,
-        )}
+        ))}
     }
 }
 
 #[inline(always)]
-fn validate_nest_assumptions<V: IsSliceomorphic>(len: usize, prefix: &'static str) {
+fn validate_nest_assumptions<V: IsSliceomorphic>(len: usize) -> Result<(), NestError> {
     validate_alignment_and_size::<V>();
-    assert_ne!(
-        0, V::LEN,
-        "cannot nest arrays of length 0",
-    );
-    assert_eq!(
-        0, len % V::LEN,
-        "cannot view slice of length {} as {}[[_; {}]]",
-        len, prefix, V::LEN,
-    );
+    if V::LEN == 0 {
+        return Err(NestError::ZeroLengthArray);
+    }
+    if !len.is_multiple_of(V::LEN) {
+        return Err(NestError::NotDivisible { len, array_len: V::LEN });
+    }
+    Ok(())
 }
 
 impl<T> SliceArrayExt<T> for [T] {
     fn as_array<V: IsSliceomorphic<Element=T>>(&self) -> &V {
-        validate_as_array_assumptions::<V>(self.len(), "&");
+        self.try_as_array().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn as_mut_array<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut V {
+        self.try_as_mut_array().unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    fn try_as_array<V: IsSliceomorphic<Element=T>>(&self) -> Result<&V, ArrayError> {
+        validate_as_array_assumptions::<V>(self.len())?;
 
         // &self.nest()[0]  // <-- would not work for V::LEN = 0
 
@@ -331,11 +537,11 @@ impl<T> SliceArrayExt<T> for [T] {
         // - pointer must be aligned
         // - pointer must be valid for given size
         // - lifetimes are unchecked
-        unsafe { (self.as_ptr() as *const V).as_ref().unwrap() }
+        unsafe { Ok((self.as_ptr() as *const V).as_ref().unwrap()) }
     }
 
-    fn as_mut_array<V: IsSliceomorphic<Element=T>>(&mut self) -> &mut V {
-        validate_as_array_assumptions::<V>(self.len(), "&mut ");
+    fn try_as_mut_array<V: IsSliceomorphic<Element=T>>(&mut self) -> Result<&mut V, ArrayError> {
+        validate_as_array_assumptions::<V>(self.len())?;
 
         // &mut self.nest_mut()[0]  // <-- would not work for V::LEN = 0
 
@@ -344,23 +550,375 @@ impl<T> SliceArrayExt<T> for [T] {
         // - pointer must be valid for given size
         // - lifetimes are unchecked
         // - aliasing guarantees of &mut are unchecked
-        unsafe { (self.as_mut_ptr() as *mut V).as_mut().unwrap() }
+        unsafe { Ok((self.as_mut_ptr() as *mut V).as_mut().unwrap()) }
     }
 }
 
 #[inline(always)]
-fn validate_as_array_assumptions<V: IsSliceomorphic>(len: usize, prefix: &'static str) {
+fn validate_as_array_assumptions<V: IsSliceomorphic>(len: usize) -> Result<(), ArrayError> {
     validate_alignment_and_size::<V>();
-    assert_eq!(
-        len, V::LEN,
-        "cannot view slice of length {} as {}[_; {}]",
-        len, prefix, V::LEN,
-    );
+    if len != V::LEN {
+        return Err(ArrayError { len, array_len: V::LEN });
+    }
+    Ok(())
+}
+
+/// Permits converting a `Vec` of arrays into a flat `Vec` without copying.
+///
+/// # Implementors
+///
+/// The method is available on `Vec<[T; n]>` for all `T` and `n`.
+///
+/// # Notice
+///
+/// The existence of this trait is an implementation detail.  Future versions may
+/// split it up, merge or rename it.
+/// Therefore, **please do NOT use this trait as a generic bound in your code.**
+#[cfg(feature = "alloc")]
+pub trait VecFlatExt<T> {
+    /// Convert `Vec<[T; n]>` into `Vec<T>` without copying.
+    ///
+    /// This reuses the original allocation in place; it does not need to
+    /// know anything about allocators, because a `Vec<[T; n]>`'s buffer is
+    /// already laid out exactly like a `Vec<T>`'s buffer of `n` times the
+    /// length.
+    fn into_flat(self) -> Vec<T>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> VecFlatExt<T> for Vec<[T; N]> {
+    fn into_flat(self) -> Vec<T> {
+        let len = self.len();
+        let cap = self.capacity();
+        let mut v = mem::ManuallyDrop::new(self);
+        let ptr = v.as_mut_ptr() as *mut T;
+
+        // Mirrors the reasoning in `Vec<[T; N]>::into_flattened` in std:
+        // for a zero-sized `T`, `len * N` may overflow `usize` even though
+        // no allocation is actually involved, so it must be checked; the
+        // resulting `Vec` is given a capacity of `usize::MAX`, as is done
+        // for all `Vec`s of a zero-sized type.
+        let (new_len, new_cap) = if mem::size_of::<T>() == 0 {
+            (len.checked_mul(N).expect("vec len overflow"), usize::MAX)
+        } else {
+            (len * N, cap * N)
+        };
+
+        // UNSAFETY: (Vec::from_raw_parts)
+        // - ptr was allocated by Vec's global allocator, and is being
+        //   reinterpreted as a pointer to the first `T` of the same buffer
+        // - new_len <= new_cap
+        // - new_cap matches the capacity the buffer was allocated with,
+        //   measured in units of `T` rather than `[T; N]`
+        unsafe { Vec::from_raw_parts(ptr, new_len, new_cap) }
+    }
+}
+
+/// Permits converting a flat `Vec` into a `Vec` of arrays, without copying
+/// when possible.
+///
+/// # Implementors
+///
+/// The method is available on `Vec<T>` for all `T`.
+///
+/// # Notice
+///
+/// The existence of this trait is an implementation detail.  Future versions may
+/// split it up, merge or rename it.
+/// Therefore, **please do NOT use this trait as a generic bound in your code.**
+#[cfg(feature = "alloc")]
+pub trait VecNestExt<T> {
+    /// Convert `Vec<T>` into `Vec<[T; n]>`, reusing the original allocation
+    /// when possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if `V::LEN` is zero, or if the
+    /// length of the `Vec` is not divisible by `V::LEN`. See [`NestError`].
+    ///
+    /// Unlike [`SliceNestExt::try_nest`], this is never foiled by the
+    /// `Vec`'s *capacity* not being divisible by `V::LEN` (which `len` alone
+    /// does not guarantee). When that happens, the `Vec` is first shrunk to
+    /// fit its length; `shrink_to_fit` is not guaranteed to make `capacity`
+    /// exact, though, so on the rare allocator where it doesn't, the elements
+    /// are instead moved one-by-one into a freshly, exactly-sized `Vec`.
+    /// Either way, this always succeeds once the length check above passes.
+    fn into_nest<V: IsSliceomorphic<Element=T>>(self) -> Result<Vec<V>, NestError>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VecNestExt<T> for Vec<T> {
+    fn into_nest<V: IsSliceomorphic<Element=T>>(self) -> Result<Vec<V>, NestError> {
+        validate_nest_assumptions::<V>(self.len())?;
+
+        let mut v = self;
+        if !v.capacity().is_multiple_of(V::LEN) {
+            v.shrink_to_fit();
+        }
+
+        if !v.capacity().is_multiple_of(V::LEN) {
+            // `shrink_to_fit` does not guarantee `capacity() == len()`, so the
+            // capacity may still not be a whole number of `V`s. Reconstructing
+            // a `Vec<V>` from it would deallocate with the wrong layout, so
+            // fall back to moving the elements into a fresh, exactly-sized
+            // `Vec<V>` instead.
+            return Ok(move_into_nested_vec::<T, V>(v));
+        }
+
+        let len = v.len();
+        let cap = v.capacity();
+        let mut v = mem::ManuallyDrop::new(v);
+        let ptr = v.as_mut_ptr() as *mut V;
+
+        // UNSAFETY: (Vec::from_raw_parts)
+        // - ptr was allocated by Vec's global allocator, and is being
+        //   reinterpreted as a pointer to the first `[T; n]` of the same buffer
+        // - len / V::LEN <= cap / V::LEN
+        // - cap is a whole number of `V`s, just checked above
+        unsafe { Ok(Vec::from_raw_parts(ptr, len / V::LEN, cap / V::LEN)) }
+    }
+}
+
+/// Moves the elements of `v` into a freshly-allocated, exactly-sized `Vec<V>`,
+/// without requiring `T: Clone`.
+///
+/// This is the fallback used by [`VecNestExt::into_nest`] for the rare
+/// allocators where `shrink_to_fit` cannot bring `v`'s capacity down to a
+/// whole number of `V`s.
+#[cfg(feature = "alloc")]
+fn move_into_nested_vec<T, V: IsSliceomorphic<Element=T>>(mut v: Vec<T>) -> Vec<V> {
+    let new_len = v.len() / V::LEN;
+    let mut out = Vec::with_capacity(new_len);
+
+    // UNSAFETY:
+    // - each `V` is read out of `v`'s buffer by value (a bitwise move) and
+    //   immediately handed to `out`, so it is never dropped in place
+    // - `v.set_len(0)` afterwards stops `v` from (double-)dropping those same
+    //   `T`s when it is itself dropped; `v`'s buffer is then freed as plain,
+    //   uninitialized memory
+    unsafe {
+        let src = v.as_ptr() as *const V;
+        for i in 0..new_len {
+            out.push(src.add(i).read());
+        }
+        v.set_len(0);
+    }
+    out
+}
+
+/// Marker trait for types that are safe to reinterpret as raw bytes, and
+/// vice versa.
+///
+/// # Safety
+///
+/// Implementing this trait asserts that every possible bit pattern of the
+/// size and alignment of `Self` is a valid value of `Self` (i.e. `Self` has
+/// no padding bytes and no invalid bit patterns). This is the same
+/// guarantee given by the `Pod` traits of crates like `bytemuck`/`zerocopy`.
+///
+/// # Notice
+///
+/// **Please do NOT use this trait in public interfaces in your code.** See
+/// the equivalent notice on [`IsSliceomorphic`].
+#[cfg(feature = "bytes")]
+pub unsafe trait ByteSafe {}
+
+#[cfg(feature = "bytes")]
+macro_rules! impl_byte_safe {
+    ($($t:ty),* $(,)?) => {
+        $( unsafe impl ByteSafe for $t {} )*
+    };
+}
+
+#[cfg(feature = "bytes")]
+impl_byte_safe!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64,
+);
+
+#[cfg(feature = "bytes")]
+unsafe impl<U: ByteSafe, const M: usize> ByteSafe for [U; M] {}
+
+/// The error type returned by [`BytesSliceExt::try_from_bytes`] and
+/// [`BytesSliceExt::try_from_bytes_mut`].
+#[cfg(feature = "bytes")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The byte slice's address is not a multiple of the target type's alignment.
+    Misaligned {
+        /// The alignment that was required.
+        align: usize,
+    },
+    /// `V` is a zero-sized type, for which a byte slice's length can never
+    /// determine the resulting slice's length.
+    ZeroSizedElement,
+    /// The byte slice's length is not a multiple of the target type's size.
+    LengthMismatch {
+        /// The length of the byte slice.
+        len: usize,
+        /// The size (in bytes) of the target element type.
+        elem_size: usize,
+    },
+}
+
+#[cfg(feature = "bytes")]
+impl fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FromBytesError::Misaligned { align } => {
+                write!(f, "byte slice is not aligned to a {}-byte boundary", align)
+            },
+            FromBytesError::ZeroSizedElement => {
+                write!(f, "cannot reinterpret a byte slice as a zero-sized type")
+            },
+            FromBytesError::LengthMismatch { len, elem_size } => {
+                write!(f, "byte slice of length {} is not a multiple of the element size {}", len, elem_size)
+            },
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl core::error::Error for FromBytesError {}
+
+/// Permits viewing a slice of [`ByteSafe`] values as raw bytes.
+///
+/// # Implementors
+///
+/// The methods are available on `&[V]` and `&mut [V]` for all `V: ByteSafe`.
+///
+/// # Notice
+///
+/// The existence of this trait is an implementation detail.  Future versions may
+/// split it up, merge or rename it.
+/// Therefore, **please do NOT use this trait as a generic bound in your code.**
+#[cfg(feature = "bytes")]
+pub trait SliceBytesExt {
+    /// View `&[V]` as `&[u8]`.
+    fn as_bytes(&self) -> &[u8];
+
+    /// View `&mut [V]` as `&mut [u8]`.
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+#[cfg(feature = "bytes")]
+impl<V: ByteSafe> SliceBytesExt for [V] {
+    fn as_bytes(&self) -> &[u8] {
+        // UNSAFETY: (core::slice::from_raw_parts)
+        // - pointer must be non-null (even for zero-length)
+        // - pointer must be aligned
+        // - pointer must be valid for given size
+        // - lifetimes are unchecked
+        unsafe {
+            slice::from_raw_parts(
+                self.as_ptr() as *const u8,
+                mem::size_of_val(self),
+            )
+        }
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        // UNSAFETY: (core::slice::from_raw_parts_mut)
+        // - pointer must be non-null (even for zero-length)
+        // - pointer must be aligned
+        // - pointer must be valid for given size
+        // - lifetimes are unchecked
+        // - aliasing guarantees of &mut are unchecked
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.as_mut_ptr() as *mut u8,
+                mem::size_of_val(self),
+            )
+        }
+    }
+}
+
+/// Permits reinterpreting a byte slice as a slice of [`ByteSafe`] values.
+///
+/// # Implementors
+///
+/// The methods are available on `&[u8]` and `&mut [u8]`.
+///
+/// # Notice
+///
+/// The existence of this trait is an implementation detail.  Future versions may
+/// split it up, merge or rename it.
+/// Therefore, **please do NOT use this trait as a generic bound in your code.**
+#[cfg(feature = "bytes")]
+pub trait BytesSliceExt {
+    /// View `&[u8]` as `&[V]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error rather than panicking if the slice is not aligned
+    /// for `V`, or if its length is not an exact multiple of
+    /// `size_of::<V>()`. See [`FromBytesError`].
+    fn try_from_bytes<V: ByteSafe>(&self) -> Result<&[V], FromBytesError>;
+
+    /// View `&mut [u8]` as `&mut [V]`.
+    ///
+    /// # Errors
+    ///
+    /// See [`try_from_bytes`][Self::try_from_bytes].
+    fn try_from_bytes_mut<V: ByteSafe>(&mut self) -> Result<&mut [V], FromBytesError>;
+}
+
+#[cfg(feature = "bytes")]
+impl BytesSliceExt for [u8] {
+    fn try_from_bytes<V: ByteSafe>(&self) -> Result<&[V], FromBytesError> {
+        validate_from_bytes_assumptions::<V>(self.as_ptr() as usize, self.len())?;
+
+        // UNSAFETY: (core::slice::from_raw_parts)
+        // - pointer must be non-null (even for zero-length)
+        // - pointer must be aligned: checked above
+        // - pointer must be valid for given size: checked above
+        // - lifetimes are unchecked
+        unsafe { Ok(slice::from_raw_parts(
+            self.as_ptr() as *const V,
+            self.len() / mem::size_of::<V>(),
+        ))}
+    }
+
+    fn try_from_bytes_mut<V: ByteSafe>(&mut self) -> Result<&mut [V], FromBytesError> {
+        validate_from_bytes_assumptions::<V>(self.as_ptr() as usize, self.len())?;
+
+        // UNSAFETY: (core::slice::from_raw_parts_mut)
+        // - pointer must be non-null (even for zero-length)
+        // - pointer must be aligned: checked above
+        // - pointer must be valid for given size: checked above
+        // - lifetimes are unchecked
+        // - aliasing guarantees of &mut are unchecked
+        unsafe { Ok(slice::from_raw_parts_mut(
+            self.as_mut_ptr() as *mut V,
+            self.len() / mem::size_of::<V>(),
+        ))}
+    }
+}
+
+#[cfg(feature = "bytes")]
+#[inline(always)]
+fn validate_from_bytes_assumptions<V>(addr: usize, len: usize) -> Result<(), FromBytesError> {
+    let align = mem::align_of::<V>();
+    if !addr.is_multiple_of(align) {
+        return Err(FromBytesError::Misaligned { align });
+    }
+
+    let elem_size = mem::size_of::<V>();
+    if elem_size == 0 {
+        return Err(FromBytesError::ZeroSizedElement);
+    }
+    if !len.is_multiple_of(elem_size) {
+        return Err(FromBytesError::LengthMismatch { len, elem_size });
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     pub use super::prelude::*;
+    // brings `Vec`/`vec!` into scope; see the crate-root imports
+    use super::*;
 
     #[test]
     fn inference_lattice() {
@@ -390,6 +948,144 @@ mod tests {
         assert_eq!(v.flat_mut(), &[] as &[()]);
     }
 
+    mod fallible {
+        use super::super::*;
+
+        #[test]
+        fn try_nest_ok() {
+            let v = vec![(); 9];
+            assert!(v.try_nest::<[(); 3]>().is_ok());
+        }
+
+        #[test]
+        fn try_nest_not_multiple() {
+            let v = vec![(); 8];
+            assert_eq!(
+                v.try_nest::<[(); 3]>().unwrap_err(),
+                NestError::NotDivisible { len: 8, array_len: 3 },
+            );
+        }
+
+        #[test]
+        fn try_nest_zero() {
+            let v: Vec<()> = vec![];
+            assert_eq!(
+                v.try_nest::<[(); 0]>().unwrap_err(),
+                NestError::ZeroLengthArray,
+            );
+        }
+
+        #[test]
+        fn try_as_array_ok() {
+            let v = vec![(); 3];
+            assert!(v.try_as_array::<[(); 3]>().is_ok());
+        }
+
+        #[test]
+        fn try_as_array_wrong_length() {
+            let v = vec![(); 6];
+            assert_eq!(
+                v.try_as_array::<[(); 3]>().unwrap_err(),
+                ArrayError { len: 6, array_len: 3 },
+            );
+        }
+
+        #[test]
+        fn try_flat_ok() {
+            let v = vec![[2i32, 2, 2], [7, 7, 7]];
+            assert_eq!(v.try_flat(), Ok(&[2, 2, 2, 7, 7, 7][..]));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod owned {
+        use super::super::*;
+
+        #[test]
+        fn into_flat() {
+            let vec = vec![[2i32, 2, 2], [7, 7, 7]];
+            assert_eq!(vec.into_flat(), vec![2, 2, 2, 7, 7, 7]);
+        }
+
+        #[test]
+        fn into_flat_zero_sized() {
+            let vec = vec![[(); 3]; 5];
+            assert_eq!(vec.into_flat(), vec![(); 15]);
+        }
+
+        #[test]
+        fn into_nest_ok() {
+            let vec = vec![2i32, 2, 2, 7, 7, 7];
+            assert_eq!(vec.into_nest(), Ok(vec![[2, 2, 2], [7, 7, 7]]));
+        }
+
+        #[test]
+        fn into_nest_not_multiple() {
+            let vec = vec![2i32, 2, 2, 7, 7];
+            assert_eq!(
+                vec.into_nest::<[i32; 3]>().unwrap_err(),
+                NestError::NotDivisible { len: 5, array_len: 3 },
+            );
+        }
+
+        #[test]
+        fn into_nest_odd_capacity() {
+            // start with a capacity that is deliberately not a multiple of 3,
+            // to exercise the `shrink_to_fit` fallback in `into_nest`
+            let mut vec = Vec::with_capacity(7);
+            vec.extend([2i32, 2, 2, 7, 7, 7]);
+            assert_eq!(vec.into_nest(), Ok(vec![[2, 2, 2], [7, 7, 7]]));
+        }
+
+        #[test]
+        fn roundtrip() {
+            let vec = vec![[2i32, 2, 2], [7, 7, 7], [4, 4, 4]];
+            let original = vec.clone();
+            let roundtripped: Vec<[i32; 3]> = vec.into_flat().into_nest().unwrap();
+            assert_eq!(original, roundtripped);
+        }
+    }
+
+    #[cfg(feature = "bytes")]
+    mod bytes {
+        use super::super::*;
+
+        #[test]
+        fn as_bytes_roundtrip() {
+            let v: Vec<[f32; 3]> = vec![[2.0, 2.0, 2.0], [7.0, 7.0, 7.0]];
+            let bytes = v.as_bytes();
+            assert_eq!(bytes.len(), 2 * 3 * 4);
+
+            let back: &[[f32; 3]] = bytes.try_from_bytes().unwrap();
+            assert_eq!(back, &v[..]);
+        }
+
+        #[test]
+        fn try_from_bytes_misaligned() {
+            // a Vec<u32>'s buffer is 4-byte aligned, so slicing off the first
+            // byte guarantees a misaligned start for a `u32` reinterpretation
+            let v: Vec<u32> = vec![0, 0, 0];
+            let bytes = v.as_bytes();
+            let misaligned = &bytes[1..];
+            assert_eq!(
+                misaligned.try_from_bytes::<u32>().unwrap_err(),
+                FromBytesError::Misaligned { align: ::core::mem::align_of::<u32>() },
+            );
+        }
+
+        #[test]
+        fn try_from_bytes_length_mismatch() {
+            // derive the bytes from an aligned Vec<u32> buffer, then truncate,
+            // so that only the length (and not the alignment) is at fault
+            let v: Vec<u32> = vec![0, 0];
+            let bytes = &v.as_bytes()[..6];
+            assert_eq!(
+                bytes.try_from_bytes::<u32>().unwrap_err(),
+                FromBytesError::LengthMismatch { len: 6, elem_size: 4 },
+            );
+        }
+    }
+
     mod failures {
         use super::super::*;
 